@@ -0,0 +1,116 @@
+//! Crash-safe file writes shared by every command that touches `.mx/`.
+//!
+//! Writing directly to the destination path can leave readers with a
+//! half-written file if the process dies mid-write. Instead we write the
+//! full contents to a temporary file next to the destination, `fsync` it,
+//! then `rename` it into place. A rename onto an existing path is atomic on
+//! every platform we care about except Windows, which is handled separately
+//! below.
+
+use crate::error::AppError;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::Path;
+
+/// Writes `contents` to `path`, creating parent directories as needed, such
+/// that readers never observe a partially written file.
+pub(crate) fn write_atomic(path: &Path, contents: &[u8]) -> Result<(), AppError> {
+    let dir = path.parent().ok_or_else(|| {
+        AppError::other(format!("{} has no parent directory", path.display()))
+    })?;
+    fs::create_dir_all(dir)?;
+
+    let temp_path = dir.join(temp_file_name(path));
+
+    let mut file = File::create(&temp_path)?;
+    file.write_all(contents)?;
+    file.sync_all()?;
+    drop(file);
+
+    if let Err(err) = fs::rename(&temp_path, path) {
+        // Windows refuses to rename onto an existing file; fall back to
+        // removing the destination first and retrying once.
+        if cfg!(windows) && path.exists() {
+            fs::remove_file(path)?;
+            fs::rename(&temp_path, path)?;
+        } else {
+            let _ = fs::remove_file(&temp_path);
+            return Err(err.into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Builds a collision-resistant temp file name next to `path`, e.g.
+/// `tasks.md.3f9a2c17.tmp` for `tasks.md`.
+fn temp_file_name(path: &Path) -> String {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "mix".to_string());
+    format!("{file_name}.{:08x}.tmp", random_suffix())
+}
+
+fn random_suffix() -> u32 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    let pid = std::process::id();
+    nanos ^ pid.wrapping_mul(0x9E37_79B9)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn writes_full_contents() {
+        let temp = tempdir().unwrap();
+        let path = temp.path().join("tasks.md");
+
+        write_atomic(&path, b"hello world").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "hello world");
+    }
+
+    #[test]
+    fn creates_parent_directories() {
+        let temp = tempdir().unwrap();
+        let path = temp.path().join("pending/tasks.md");
+
+        write_atomic(&path, b"nested").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "nested");
+    }
+
+    #[test]
+    fn overwrites_existing_file_without_truncating_partially() {
+        let temp = tempdir().unwrap();
+        let path = temp.path().join("tasks.md");
+        fs::write(&path, "old content that is much longer than new").unwrap();
+
+        write_atomic(&path, b"new").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+    }
+
+    #[test]
+    fn leaves_no_temp_file_behind() {
+        let temp = tempdir().unwrap();
+        let path = temp.path().join("tasks.md");
+
+        write_atomic(&path, b"content").unwrap();
+
+        let leftovers: Vec<_> = fs::read_dir(temp.path())
+            .unwrap()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path() != path)
+            .collect();
+        assert!(leftovers.is_empty(), "temp file was not cleaned up: {leftovers:?}");
+    }
+}
@@ -1,6 +1,6 @@
-use crate::commands::touch::{find_project_root, resolve_path, validate_path};
+use crate::commands::touch::{resolve_path, validate_path};
 use crate::error::AppError;
-use std::fs;
+use crate::storage::SnippetStorage;
 
 /// Displays the contents of a context file from the `.mx/` directory.
 ///
@@ -10,6 +10,9 @@ use std::fs;
 /// - Pending prefix (pd-tk, pd-rq, etc.)
 /// - Custom relative paths with automatic .md extension
 ///
+/// Reads go through the configured `Storage` backend, so this works the same
+/// way whether `.mx/` is local or on a remote host (see `MX_REMOTE`).
+///
 /// # Arguments
 ///
 /// * `key` - The key to resolve to a file path (e.g., "tk", "rq", "docs/spec")
@@ -30,22 +33,15 @@ use std::fs;
 /// let content = cat_context("tk").expect("Failed to read tasks");
 /// println!("{}", content);
 /// ```
-pub fn cat(key: &str) -> Result<String, AppError> {
-    // Find the project root directory (where .mx/ directory is or should be)
-    let root = find_project_root()?;
-
+pub fn cat(storage: &SnippetStorage, key: &str) -> Result<String, AppError> {
     // Resolve the key to a relative path (e.g., "tk" -> "tasks.md")
     let relative_path = resolve_path(key);
 
     // Validate the path to prevent traversal attacks
     validate_path(key, &relative_path)?;
 
-    // Build the full path to the file
-    let mx_dir = root.join(".mx");
-    let full_path = mx_dir.join(&relative_path);
-
     // Check if the file exists
-    if !full_path.exists() {
+    if !storage.exists(&relative_path)? {
         return Err(AppError::not_found(format!(
             "⚠️ Context file not found: {}",
             relative_path.display()
@@ -53,7 +49,7 @@ pub fn cat(key: &str) -> Result<String, AppError> {
     }
 
     // Check if it's a file (not a directory)
-    if !full_path.is_file() {
+    if !storage.is_file(&relative_path)? {
         return Err(AppError::not_found(format!(
             "⚠️ Path is not a file: {}",
             relative_path.display()
@@ -61,10 +57,12 @@ pub fn cat(key: &str) -> Result<String, AppError> {
     }
 
     // Read and return the file contents
-    fs::read_to_string(&full_path).map_err(|e| {
-        AppError::Io(std::io::Error::new(
-            e.kind(),
-            format!("Failed to read {}: {}", relative_path.display(), e),
+    let bytes = storage.read(&relative_path)?;
+    String::from_utf8(bytes).map_err(|e| {
+        AppError::other(format!(
+            "Failed to read {}: {}",
+            relative_path.display(),
+            e
         ))
     })
 }
@@ -72,36 +70,34 @@ pub fn cat(key: &str) -> Result<String, AppError> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::env;
     use std::fs;
+    use std::path::Path;
     use tempfile::tempdir;
 
+    fn storage_in(dir: &Path) -> SnippetStorage {
+        SnippetStorage::at_root(dir.to_path_buf())
+    }
+
     #[test]
     fn cat_reads_existing_file() {
         let temp = tempdir().unwrap();
-        env::set_current_dir(&temp).unwrap();
+        let storage = storage_in(temp.path());
 
         // Create a context file with known content
-        let mx_dir = temp.path().join(".mx");
-        fs::create_dir_all(&mx_dir).unwrap();
-        let tasks_path = mx_dir.join("tasks.md");
         let expected_content = "# Test Tasks\n\n- Task 1\n- Task 2\n";
-        fs::write(&tasks_path, expected_content).unwrap();
+        storage.write(Path::new("tasks.md"), expected_content.as_bytes()).unwrap();
 
         // Read it back using cat
-        let result = cat("tk").unwrap();
+        let result = cat(&storage, "tk").unwrap();
         assert_eq!(result, expected_content);
     }
 
     #[test]
     fn cat_returns_error_for_missing_file() {
         let temp = tempdir().unwrap();
-        env::set_current_dir(&temp).unwrap();
-
-        // Ensure .mx directory exists but file doesn't
-        fs::create_dir_all(temp.path().join(".mx")).unwrap();
+        let storage = storage_in(temp.path());
 
-        let result = cat("tk");
+        let result = cat(&storage, "tk");
         assert!(result.is_err());
         let err_msg = result.unwrap_err().to_string();
         assert!(err_msg.contains("⚠️"));
@@ -111,9 +107,9 @@ mod tests {
     #[test]
     fn cat_rejects_path_traversal() {
         let temp = tempdir().unwrap();
-        env::set_current_dir(&temp).unwrap();
+        let storage = storage_in(temp.path());
 
-        let result = cat("../etc/passwd");
+        let result = cat(&storage, "../etc/passwd");
         assert!(result.is_err());
         assert!(matches!(result.unwrap_err(), AppError::PathTraversal(_)));
     }
@@ -121,49 +117,38 @@ mod tests {
     #[test]
     fn cat_handles_empty_file() {
         let temp = tempdir().unwrap();
-        env::set_current_dir(&temp).unwrap();
+        let storage = storage_in(temp.path());
+        storage.write(Path::new("empty.md"), b"").unwrap();
 
-        // Create an empty file
-        let mx_dir = temp.path().join(".mx");
-        fs::create_dir_all(&mx_dir).unwrap();
-        let empty_path = mx_dir.join("empty.md");
-        fs::write(&empty_path, "").unwrap();
-
-        let result = cat("empty").unwrap();
+        let result = cat(&storage, "empty").unwrap();
         assert_eq!(result, "");
     }
 
     #[test]
     fn cat_resolves_aliases_correctly() {
         let temp = tempdir().unwrap();
-        env::set_current_dir(&temp).unwrap();
-
-        // Create files for different aliases
-        let mx_dir = temp.path().join(".mx");
-        fs::create_dir_all(&mx_dir).unwrap();
+        let storage = storage_in(temp.path());
 
         // Standard alias
         let content = "requirements content";
-        fs::write(mx_dir.join("requirements.md"), content).unwrap();
-        assert_eq!(cat("rq").unwrap(), content);
+        storage.write(Path::new("requirements.md"), content.as_bytes()).unwrap();
+        assert_eq!(cat(&storage, "rq").unwrap(), content);
 
         // Nested alias
-        fs::create_dir_all(mx_dir.join("pending")).unwrap();
         let nested_content = "pending tasks";
-        fs::write(mx_dir.join("pending/tasks.md"), nested_content).unwrap();
-        assert_eq!(cat("pdt").unwrap(), nested_content);
+        storage.write(Path::new("pending/tasks.md"), nested_content.as_bytes()).unwrap();
+        assert_eq!(cat(&storage, "pdt").unwrap(), nested_content);
     }
 
     #[test]
     fn cat_errors_on_directory() {
         let temp = tempdir().unwrap();
-        env::set_current_dir(&temp).unwrap();
+        let storage = storage_in(temp.path());
 
         // Create a directory with .md extension to simulate the edge case
-        let mx_dir = temp.path().join(".mx");
-        fs::create_dir_all(mx_dir.join("somedir.md")).unwrap();
+        fs::create_dir_all(temp.path().join(".mx/somedir.md")).unwrap();
 
-        let result = cat("somedir.md");
+        let result = cat(&storage, "somedir.md");
         assert!(result.is_err());
         let err_msg = result.unwrap_err().to_string();
         assert!(err_msg.contains("⚠️"));
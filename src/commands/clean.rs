@@ -0,0 +1,35 @@
+use crate::commands::touch::{resolve_path, validate_path};
+use crate::error::AppError;
+use crate::storage::SnippetStorage;
+
+pub struct CleanOutcome {
+    pub message: String,
+}
+
+pub fn clean(storage: &SnippetStorage, key: Option<String>) -> Result<CleanOutcome, AppError> {
+    match key {
+        None => {
+            let removed = storage.remove_all()?;
+            let message = if removed {
+                "Removed .mx directory".to_string()
+            } else {
+                ".mx directory not found".to_string()
+            };
+            Ok(CleanOutcome { message })
+        }
+        Some(k) => {
+            let relative_path = resolve_path(&k);
+            validate_path(&k, &relative_path)?;
+
+            if !storage.exists(&relative_path)? {
+                return Err(AppError::not_found(format!(
+                    "File not found: {}",
+                    relative_path.display()
+                )));
+            }
+
+            storage.remove(&relative_path)?;
+            Ok(CleanOutcome { message: format!("Removed {}", relative_path.display()) })
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::error::AppError;
+
+/// Abstracts over the system clipboard so commands can be tested without a
+/// real display server.
+pub trait Clipboard {
+    fn paste(&self) -> Result<String, AppError>;
+    fn copy(&self, text: &str) -> Result<(), AppError>;
+}
+
+struct SystemClipboard;
+
+impl Clipboard for SystemClipboard {
+    fn paste(&self) -> Result<String, AppError> {
+        arboard::Clipboard::new()
+            .and_then(|mut clipboard| clipboard.get_text())
+            .map_err(|err| AppError::other(format!("failed to read clipboard: {err}")))
+    }
+
+    fn copy(&self, text: &str) -> Result<(), AppError> {
+        arboard::Clipboard::new()
+            .and_then(|mut clipboard| clipboard.set_text(text.to_string()))
+            .map_err(|err| AppError::other(format!("failed to write clipboard: {err}")))
+    }
+}
+
+/// Builds the clipboard implementation to use. Always returns the system
+/// clipboard; the environment is not consulted and no fake can be selected
+/// through it.
+pub fn clipboard_from_env() -> Result<Box<dyn Clipboard>, AppError> {
+    Ok(Box::new(SystemClipboard))
+}
@@ -0,0 +1,47 @@
+use crate::commands::clipboard::Clipboard;
+use crate::commands::touch::{resolve_path, validate_path};
+use crate::error::AppError;
+use crate::storage::SnippetStorage;
+
+pub struct CopySnippet<'a> {
+    pub query: &'a str,
+}
+
+pub struct CopyResult {
+    pub key: String,
+    pub relative_path: String,
+}
+
+impl CopySnippet<'_> {
+    /// Reads the context file matching `query` and copies its contents to
+    /// the clipboard.
+    pub fn execute(
+        &self,
+        storage: &SnippetStorage,
+        clipboard: &dyn Clipboard,
+    ) -> Result<CopyResult, AppError> {
+        let relative_path = resolve_path(self.query);
+        validate_path(self.query, &relative_path)?;
+
+        if !storage.is_file(&relative_path)? {
+            return Err(AppError::not_found(format!(
+                "⚠️ Context file not found: {}",
+                relative_path.display()
+            )));
+        }
+
+        let bytes = storage.read(&relative_path)?;
+        let content = String::from_utf8(bytes).map_err(|err| {
+            AppError::other(format!(
+                "Failed to read {}: {err}",
+                relative_path.display()
+            ))
+        })?;
+        clipboard.copy(&content)?;
+
+        Ok(CopyResult {
+            key: self.query.to_string(),
+            relative_path: relative_path.display().to_string(),
+        })
+    }
+}
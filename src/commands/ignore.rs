@@ -0,0 +1,200 @@
+//! Per-directory `.mxignore` (and `.gitignore`) pattern matching for
+//! `mix ls`, built while walking `.mx/` so patterns in nested directories
+//! take precedence over patterns from their ancestors.
+//!
+//! This only hides files from listings: `cat`/`touch` resolve aliases
+//! directly against storage and never consult this tree, so an explicitly
+//! named alias stays reachable even when ignored.
+
+use crate::error::AppError;
+use crate::storage::SnippetStorage;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+const IGNORE_FILES: &[&str] = &[".mxignore", ".gitignore"];
+
+pub struct IgnoreTree {
+    patterns_by_dir: HashMap<PathBuf, Vec<String>>,
+}
+
+impl IgnoreTree {
+    /// Reads every `.mxignore`/`.gitignore` under `directories`, which must
+    /// include the root represented by an empty `PathBuf`.
+    pub fn build(storage: &SnippetStorage, directories: &[PathBuf]) -> Result<Self, AppError> {
+        let mut patterns_by_dir = HashMap::new();
+
+        for dir in directories {
+            let mut patterns = Vec::new();
+            for ignore_file in IGNORE_FILES {
+                let path = dir.join(ignore_file);
+                if storage.is_file(&path)? {
+                    let bytes = storage.read(&path)?;
+                    patterns.extend(parse_patterns(&String::from_utf8_lossy(&bytes)));
+                }
+            }
+            if !patterns.is_empty() {
+                patterns_by_dir.insert(dir.clone(), patterns);
+            }
+        }
+
+        Ok(Self { patterns_by_dir })
+    }
+
+    /// Checks the file name of `relative_path` against the patterns of every
+    /// ignore file from its own directory up to the root, nearest first; a
+    /// match at any level ignores it.
+    pub fn is_ignored(&self, relative_path: &Path) -> bool {
+        let Some(name) = relative_path.file_name() else {
+            return false;
+        };
+        let name = name.to_string_lossy();
+
+        let mut current = relative_path.parent();
+        loop {
+            let key: &Path = current.unwrap_or_else(|| Path::new(""));
+            if let Some(patterns) = self.patterns_by_dir.get(key) {
+                if patterns.iter().any(|pattern| matches_glob(pattern, &name)) {
+                    return true;
+                }
+            }
+
+            match current {
+                Some(dir) if !dir.as_os_str().is_empty() => current = dir.parent(),
+                _ => return false,
+            }
+        }
+    }
+}
+
+fn parse_patterns(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| line.trim_end_matches('/').to_string())
+        .collect()
+}
+
+/// A small subset of gitignore-style globbing: exact names, or `*` as a
+/// wildcard spanning any number of characters within a single path segment.
+fn matches_glob(pattern: &str, name: &str) -> bool {
+    if !pattern.contains('*') {
+        return pattern == name;
+    }
+
+    let parts: Vec<&str> = pattern.split('*').collect();
+    let (first, last) = (parts[0], *parts.last().unwrap());
+    if !name.starts_with(first) || !name.ends_with(last) {
+        return false;
+    }
+    if first.len() + last.len() > name.len() {
+        return false;
+    }
+
+    let mut rest = &name[first.len()..name.len() - last.len()];
+    for part in &parts[1..parts.len() - 1] {
+        match rest.find(part) {
+            Some(idx) => rest = &rest[idx + part.len()..],
+            None => return false,
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::cat;
+    use crate::commands::list_snippets;
+    use crate::commands::touch;
+    use tempfile::tempdir;
+
+    fn storage_in(dir: &Path) -> SnippetStorage {
+        SnippetStorage::at_root(dir.to_path_buf())
+    }
+
+    #[test]
+    fn ignores_exact_name_match() {
+        let temp = tempdir().unwrap();
+        let storage = storage_in(temp.path());
+        storage.write(Path::new(".mxignore"), b"scratch.md\n").unwrap();
+        storage.write(Path::new("scratch.md"), b"x").unwrap();
+        storage.write(Path::new("tasks.md"), b"x").unwrap();
+
+        let tree = IgnoreTree::build(&storage, &[PathBuf::new()]).unwrap();
+
+        assert!(tree.is_ignored(Path::new("scratch.md")));
+        assert!(!tree.is_ignored(Path::new("tasks.md")));
+    }
+
+    #[test]
+    fn ignores_glob_pattern() {
+        let temp = tempdir().unwrap();
+        let storage = storage_in(temp.path());
+        storage.write(Path::new(".mxignore"), b"*.tmp\n").unwrap();
+        storage.write(Path::new("draft.tmp"), b"x").unwrap();
+
+        let tree = IgnoreTree::build(&storage, &[PathBuf::new()]).unwrap();
+
+        assert!(tree.is_ignored(Path::new("draft.tmp")));
+    }
+
+    #[test]
+    fn overlapping_glob_prefix_and_suffix_does_not_panic() {
+        let temp = tempdir().unwrap();
+        let storage = storage_in(temp.path());
+        storage.write(Path::new(".mxignore"), b"tmp*tmp\n").unwrap();
+        storage.write(Path::new("tmp"), b"x").unwrap();
+
+        let tree = IgnoreTree::build(&storage, &[PathBuf::new()]).unwrap();
+
+        assert!(!tree.is_ignored(Path::new("tmp")));
+    }
+
+    #[test]
+    fn nested_ignore_file_only_applies_within_its_directory() {
+        let temp = tempdir().unwrap();
+        let storage = storage_in(temp.path());
+        storage.write(Path::new("pending/.mxignore"), b"scratch.md\n").unwrap();
+        storage.write(Path::new("pending/scratch.md"), b"x").unwrap();
+        storage.write(Path::new("scratch.md"), b"x").unwrap();
+
+        let directories = vec![PathBuf::new(), PathBuf::from("pending")];
+        let tree = IgnoreTree::build(&storage, &directories).unwrap();
+
+        assert!(tree.is_ignored(Path::new("pending/scratch.md")));
+        assert!(!tree.is_ignored(Path::new("scratch.md")));
+    }
+
+    #[test]
+    fn ignores_comments_and_blank_lines() {
+        let temp = tempdir().unwrap();
+        let storage = storage_in(temp.path());
+        storage
+            .write(Path::new(".mxignore"), b"# comment\n\nscratch.md\n")
+            .unwrap();
+        storage.write(Path::new("scratch.md"), b"x").unwrap();
+
+        let tree = IgnoreTree::build(&storage, &[PathBuf::new()]).unwrap();
+
+        assert!(tree.is_ignored(Path::new("scratch.md")));
+    }
+
+    #[test]
+    fn ignored_file_is_hidden_from_list_but_still_reachable_by_cat_and_touch() {
+        let temp = tempdir().unwrap();
+        let storage = storage_in(temp.path());
+        storage.write(Path::new(".mxignore"), b"scratch.md\n").unwrap();
+        storage.write(Path::new("scratch.md"), b"hidden").unwrap();
+        storage.write(Path::new("tasks.md"), b"visible").unwrap();
+
+        let entries = list_snippets::list(&storage).unwrap();
+        assert!(entries.iter().all(|entry| entry.relative_path != "scratch.md"));
+        assert!(entries.iter().any(|entry| entry.relative_path == "tasks.md"));
+
+        assert_eq!(cat::cat(&storage, "scratch.md").unwrap(), "hidden");
+
+        let result = touch::touch(&storage, "scratch.md", false).unwrap();
+        assert!(result.existed);
+    }
+}
@@ -0,0 +1,87 @@
+use crate::commands::ignore::IgnoreTree;
+use crate::error::AppError;
+use crate::storage::SnippetStorage;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+pub struct Entry {
+    pub key: String,
+    pub relative_path: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+}
+
+/// Enumerates every context file under storage, hiding anything matched by a
+/// `.mxignore`/`.gitignore` file (see `commands::ignore`). Ignore files
+/// themselves are never listed.
+pub fn list(storage: &SnippetStorage) -> Result<Vec<Entry>, AppError> {
+    let relative_paths = storage.list()?;
+    let ignore_tree = IgnoreTree::build(storage, &ancestor_directories(&relative_paths))?;
+
+    let mut entries = relative_paths
+        .into_iter()
+        .filter(|path| !is_ignore_file(path) && !ignore_tree.is_ignored(path))
+        .map(|relative_path| build_entry(storage, relative_path))
+        .collect::<Vec<_>>();
+    entries.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+    Ok(entries)
+}
+
+fn is_ignore_file(relative_path: &Path) -> bool {
+    matches!(
+        relative_path.file_name().and_then(|name| name.to_str()),
+        Some(".mxignore") | Some(".gitignore")
+    )
+}
+
+/// Every directory that contains at least one file, plus the root, so
+/// `IgnoreTree::build` can find every `.mxignore`/`.gitignore` in play.
+fn ancestor_directories(relative_paths: &[PathBuf]) -> Vec<PathBuf> {
+    let mut directories = BTreeSet::new();
+    directories.insert(PathBuf::new());
+
+    for path in relative_paths {
+        let mut dir = path.parent();
+        while let Some(d) = dir {
+            if d.as_os_str().is_empty() {
+                break;
+            }
+            directories.insert(d.to_path_buf());
+            dir = d.parent();
+        }
+    }
+
+    directories.into_iter().collect()
+}
+
+fn build_entry(storage: &SnippetStorage, relative_path: PathBuf) -> Entry {
+    let (title, description) = read_front_matter(storage, &relative_path);
+    Entry {
+        key: relative_path.display().to_string(),
+        relative_path: relative_path.display().to_string(),
+        title,
+        description,
+    }
+}
+
+/// Pulls a title out of a leading `# Heading` line and a description out of
+/// the first non-empty line after it (or the first non-empty line of the
+/// file when there is no heading), if present.
+fn read_front_matter(storage: &SnippetStorage, relative_path: &Path) -> (Option<String>, Option<String>) {
+    let Ok(bytes) = storage.read(relative_path) else {
+        return (None, None);
+    };
+    let Ok(content) = String::from_utf8(bytes) else {
+        return (None, None);
+    };
+
+    let mut lines = content.lines().filter(|line| !line.trim().is_empty()).peekable();
+    let title = lines
+        .next_if(|line| line.starts_with("# "))
+        .and_then(|line| line.strip_prefix("# "))
+        .map(|title| title.trim().to_string());
+
+    let description = lines.next().map(|line| line.trim().to_string());
+
+    (title, description)
+}
@@ -0,0 +1,9 @@
+pub mod cat;
+pub mod clean;
+pub mod clipboard;
+pub mod copy_snippet;
+pub mod ignore;
+pub mod list_snippets;
+pub mod sync;
+pub mod touch;
+pub mod watch;
@@ -0,0 +1,218 @@
+use crate::atomic_write::write_atomic;
+use crate::error::AppError;
+use crate::fs_walk::relative_files;
+use crate::line_ending;
+use crate::storage::SnippetStorage;
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncAction {
+    Created,
+    Updated,
+    Skipped,
+}
+
+#[derive(Debug, Clone)]
+pub struct SyncEntry {
+    pub relative_path: String,
+    pub action: SyncAction,
+}
+
+pub struct SyncOutcome {
+    pub entries: Vec<SyncEntry>,
+}
+
+impl SyncOutcome {
+    pub fn created(&self) -> usize {
+        self.count(SyncAction::Created)
+    }
+
+    pub fn updated(&self) -> usize {
+        self.count(SyncAction::Updated)
+    }
+
+    pub fn skipped(&self) -> usize {
+        self.count(SyncAction::Skipped)
+    }
+
+    fn count(&self, action: SyncAction) -> usize {
+        self.entries.iter().filter(|entry| entry.action == action).count()
+    }
+}
+
+/// Copies every file under `.mx/` in `storage` into `<destination_root>/.mx`,
+/// preserving the nested structure (e.g. `pending/tasks.md`), normalizing
+/// line endings to match the destination file the same way `touch_context`
+/// does (see `line_ending`), and skipping files that are already
+/// byte-identical at the destination once normalized.
+pub fn export(storage: &SnippetStorage, destination_root: &Path) -> Result<SyncOutcome, AppError> {
+    let destination_mx = destination_root.join(".mx");
+
+    let entries = storage
+        .list()?
+        .into_iter()
+        .map(|relative_path| {
+            let content = storage.read(&relative_path)?;
+            let action = sync_file(&content, &destination_mx.join(&relative_path))?;
+            Ok(SyncEntry { relative_path: relative_path.display().to_string(), action })
+        })
+        .collect::<Result<_, AppError>>()?;
+
+    Ok(SyncOutcome { entries })
+}
+
+/// Copies every file under `<source_root>/.mx` into `.mx/` in `storage`,
+/// preserving the nested structure, normalizing line endings to match the
+/// destination file, and skipping files that are already byte-identical at
+/// the destination once normalized.
+pub fn import(storage: &SnippetStorage, source_root: &Path) -> Result<SyncOutcome, AppError> {
+    let source_mx = source_root.join(".mx");
+
+    let mut relative_paths = Vec::new();
+    relative_files(&source_mx, &source_mx, &mut relative_paths)?;
+
+    let entries = relative_paths
+        .into_iter()
+        .map(|relative_path| {
+            let content = fs::read(source_mx.join(&relative_path))?;
+            let action = sync_into_storage(storage, &relative_path, &content)?;
+            Ok(SyncEntry { relative_path: relative_path.display().to_string(), action })
+        })
+        .collect::<Result<_, AppError>>()?;
+
+    Ok(SyncOutcome { entries })
+}
+
+fn sync_file(content: &[u8], destination: &Path) -> Result<SyncAction, AppError> {
+    let existing = destination.exists().then(|| fs::read(destination)).transpose()?;
+    let normalized = normalize_for_destination(content, existing.as_deref());
+    if existing.as_deref() == Some(normalized.as_slice()) {
+        return Ok(SyncAction::Skipped);
+    }
+
+    write_atomic(destination, &normalized)?;
+    Ok(if existing.is_some() { SyncAction::Updated } else { SyncAction::Created })
+}
+
+fn sync_into_storage(
+    storage: &SnippetStorage,
+    relative_path: &Path,
+    content: &[u8],
+) -> Result<SyncAction, AppError> {
+    let existed = storage.exists(relative_path)?;
+    let existing = existed.then(|| storage.read(relative_path)).transpose()?;
+    let normalized = normalize_for_destination(content, existing.as_deref());
+    if existing.as_deref() == Some(normalized.as_slice()) {
+        return Ok(SyncAction::Skipped);
+    }
+
+    storage.write(relative_path, &normalized)?;
+    Ok(if existed { SyncAction::Updated } else { SyncAction::Created })
+}
+
+/// Rewrites `content`'s line endings to match `existing` (the destination
+/// file's current bytes, if any) the same way `touch_context` normalizes
+/// pasted clipboard content. Non-UTF-8 content is copied through unchanged.
+fn normalize_for_destination(content: &[u8], existing: Option<&[u8]>) -> Vec<u8> {
+    match std::str::from_utf8(content) {
+        Ok(text) => line_ending::normalize(text, existing, line_ending::policy_from_env()).into_bytes(),
+        Err(_) => content.to_vec(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn storage_in(dir: &Path) -> SnippetStorage {
+        fs::create_dir_all(dir.join(".mx")).unwrap();
+        SnippetStorage::at_root(dir.to_path_buf())
+    }
+
+    #[test]
+    fn export_creates_and_updates() {
+        let project = tempdir().unwrap();
+        let storage = storage_in(project.path());
+        storage.write(Path::new("tasks.md"), b"tasks").unwrap();
+        storage.write(Path::new("pending/tasks.md"), b"pending").unwrap();
+
+        let other = tempdir().unwrap();
+        fs::create_dir_all(other.path().join(".mx")).unwrap();
+        fs::write(other.path().join(".mx/tasks.md"), b"stale").unwrap();
+
+        let outcome = export(&storage, other.path()).unwrap();
+
+        assert_eq!(outcome.created(), 1);
+        assert_eq!(outcome.updated(), 1);
+        assert_eq!(fs::read(other.path().join(".mx/tasks.md")).unwrap(), b"tasks");
+        assert_eq!(fs::read(other.path().join(".mx/pending/tasks.md")).unwrap(), b"pending");
+    }
+
+    #[test]
+    fn export_skips_byte_identical_files() {
+        let project = tempdir().unwrap();
+        let storage = storage_in(project.path());
+        storage.write(Path::new("tasks.md"), b"same").unwrap();
+
+        let other = tempdir().unwrap();
+        fs::create_dir_all(other.path().join(".mx")).unwrap();
+        fs::write(other.path().join(".mx/tasks.md"), b"same").unwrap();
+
+        let outcome = export(&storage, other.path()).unwrap();
+
+        assert_eq!(outcome.skipped(), 1);
+        assert_eq!(outcome.created(), 0);
+        assert_eq!(outcome.updated(), 0);
+    }
+
+    #[test]
+    fn import_copies_nested_structure_from_another_project() {
+        let source = tempdir().unwrap();
+        fs::create_dir_all(source.path().join(".mx/pending")).unwrap();
+        fs::write(source.path().join(".mx/tasks.md"), b"tasks").unwrap();
+        fs::write(source.path().join(".mx/pending/tasks.md"), b"pending").unwrap();
+
+        let project = tempdir().unwrap();
+        let storage = storage_in(project.path());
+
+        let outcome = import(&storage, source.path()).unwrap();
+
+        assert_eq!(outcome.created(), 2);
+        assert_eq!(storage.read(Path::new("tasks.md")).unwrap(), b"tasks");
+        assert_eq!(storage.read(Path::new("pending/tasks.md")).unwrap(), b"pending");
+    }
+
+    #[test]
+    fn import_normalizes_line_endings_to_match_destination() {
+        let source = tempdir().unwrap();
+        fs::create_dir_all(source.path().join(".mx")).unwrap();
+        fs::write(source.path().join(".mx/tasks.md"), b"a\r\nb\r\n").unwrap();
+
+        let project = tempdir().unwrap();
+        let storage = storage_in(project.path());
+        storage.write(Path::new("tasks.md"), b"old\n").unwrap();
+
+        let outcome = import(&storage, source.path()).unwrap();
+
+        assert_eq!(outcome.updated(), 1);
+        assert_eq!(storage.read(Path::new("tasks.md")).unwrap(), b"a\nb\n");
+    }
+
+    #[test]
+    fn export_normalizes_line_endings_to_match_destination() {
+        let project = tempdir().unwrap();
+        let storage = storage_in(project.path());
+        storage.write(Path::new("tasks.md"), b"a\nb\n").unwrap();
+
+        let other = tempdir().unwrap();
+        fs::create_dir_all(other.path().join(".mx")).unwrap();
+        fs::write(other.path().join(".mx/tasks.md"), b"old\r\n").unwrap();
+
+        let outcome = export(&storage, other.path()).unwrap();
+
+        assert_eq!(outcome.updated(), 1);
+        assert_eq!(fs::read(other.path().join(".mx/tasks.md")).unwrap(), b"a\r\nb\r\n");
+    }
+}
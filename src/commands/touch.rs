@@ -0,0 +1,200 @@
+use crate::error::AppError;
+use crate::storage::SnippetStorage;
+use std::env;
+use std::path::{Path, PathBuf};
+
+pub struct TouchResult {
+    pub key: String,
+    pub relative_path: PathBuf,
+    pub existed: bool,
+    pub overwritten: bool,
+}
+
+/// Static aliases for the predefined context files.
+const ALIASES: &[(&str, &str)] = &[
+    ("tk", "tasks"),
+    ("rq", "requirements"),
+    ("pdt", "pending/tasks"),
+];
+
+/// Finds the project root by walking up from the current directory looking
+/// for an existing `.mx/` directory. Falls back to the current directory so
+/// that a fresh project can bootstrap its first context file.
+pub fn find_project_root() -> Result<PathBuf, AppError> {
+    let mut dir = env::current_dir()?;
+    loop {
+        if dir.join(".mx").is_dir() {
+            return Ok(dir);
+        }
+        match dir.parent() {
+            Some(parent) => dir = parent.to_path_buf(),
+            None => return env::current_dir().map_err(AppError::from),
+        }
+    }
+}
+
+/// Resolves a key (e.g. `"tk"`, `"pd-tk"`, `"tk2"`, `"docs/spec"`) to a path
+/// relative to the `.mx/` directory.
+///
+/// Supports:
+/// - Predefined aliases (`tk`, `rq`, `pdt`, ...)
+/// - Dynamic numbered aliases (`tk1`, `tk2`, ...) -> `tasks-1.md`, `tasks-2.md`
+/// - `pd-` prefix, which nests any base alias under `pending/` (`pd-tk` -> `pending/tasks.md`)
+/// - Custom relative paths, which get a `.md` extension appended if missing
+pub fn resolve_path(key: &str) -> PathBuf {
+    if let Some(base) = key.strip_prefix("pd-") {
+        let stem = resolve_alias_stem(base).unwrap_or_else(|| base.to_string());
+        return PathBuf::from(format!("pending/{stem}.md"));
+    }
+
+    if let Some(stem) = resolve_alias_stem(key) {
+        return PathBuf::from(format!("{stem}.md"));
+    }
+
+    let mut path = PathBuf::from(key);
+    if path.extension().is_none() {
+        path.set_extension("md");
+    }
+    path
+}
+
+/// Resolves `key` (with any `pd-` prefix already stripped) to its aliased
+/// stem, handling both plain aliases (`tk` -> `tasks`) and numbered aliases
+/// (`tk2` -> `tasks-2`), so `pd-tk2` composes into `pending/tasks-2.md`
+/// instead of falling back to the literal `pending/tk2.md`.
+fn resolve_alias_stem(key: &str) -> Option<String> {
+    if let Some((base, index)) = split_numbered_alias(key) {
+        if let Some(stem) = alias_stem(base) {
+            return Some(format!("{stem}-{index}"));
+        }
+    }
+    alias_stem(key)
+}
+
+/// Ensures a resolved path doesn't escape the `.mx/` directory, either by
+/// walking up via `".."` (e.g. `"../etc/passwd"`) or by being absolute to
+/// begin with (e.g. `"/etc/passwd"`), which `PathBuf::join` would otherwise
+/// let override the `.mx/` root entirely.
+pub fn validate_path(key: &str, relative_path: &Path) -> Result<(), AppError> {
+    let escapes = relative_path.components().any(|component| {
+        matches!(
+            component,
+            std::path::Component::ParentDir
+                | std::path::Component::RootDir
+                | std::path::Component::Prefix(_)
+        )
+    });
+    if escapes {
+        return Err(AppError::path_traversal(key));
+    }
+    Ok(())
+}
+
+fn alias_stem(base: &str) -> Option<String> {
+    ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == base)
+        .map(|(_, stem)| stem.to_string())
+}
+
+/// Splits e.g. `"tk2"` into `("tk", 2)`.
+fn split_numbered_alias(key: &str) -> Option<(&str, &str)> {
+    let split_at = key.find(|c: char| c.is_ascii_digit())?;
+    let (base, index) = key.split_at(split_at);
+    if base.is_empty() || index.is_empty() {
+        return None;
+    }
+    Some((base, index))
+}
+
+pub fn touch(storage: &SnippetStorage, key: &str, force: bool) -> Result<TouchResult, AppError> {
+    let relative_path = resolve_path(key);
+    validate_path(key, &relative_path)?;
+
+    let existed = storage.exists(&relative_path)?;
+
+    let overwritten = if !existed || force {
+        storage.write(&relative_path, b"")?;
+        existed && force
+    } else {
+        false
+    };
+
+    Ok(TouchResult {
+        key: key.to_string(),
+        relative_path,
+        existed,
+        overwritten,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_predefined_aliases() {
+        assert_eq!(resolve_path("tk"), PathBuf::from("tasks.md"));
+        assert_eq!(resolve_path("rq"), PathBuf::from("requirements.md"));
+        assert_eq!(resolve_path("pdt"), PathBuf::from("pending/tasks.md"));
+    }
+
+    #[test]
+    fn resolves_numbered_aliases() {
+        assert_eq!(resolve_path("tk2"), PathBuf::from("tasks-2.md"));
+        assert_eq!(resolve_path("rq10"), PathBuf::from("requirements-10.md"));
+    }
+
+    #[test]
+    fn resolves_pd_prefixed_aliases() {
+        assert_eq!(resolve_path("pd-tk"), PathBuf::from("pending/tasks.md"));
+        assert_eq!(resolve_path("pd-rq"), PathBuf::from("pending/requirements.md"));
+    }
+
+    #[test]
+    fn resolves_pd_prefixed_numbered_aliases() {
+        assert_eq!(resolve_path("pd-tk2"), PathBuf::from("pending/tasks-2.md"));
+    }
+
+    #[test]
+    fn resolves_pd_prefixed_custom_key_without_alias() {
+        assert_eq!(resolve_path("pd-notes"), PathBuf::from("pending/notes.md"));
+    }
+
+    #[test]
+    fn resolves_custom_paths_with_md_extension_appended() {
+        assert_eq!(resolve_path("docs/spec"), PathBuf::from("docs/spec.md"));
+    }
+
+    #[test]
+    fn resolves_custom_paths_with_existing_extension_unchanged() {
+        assert_eq!(resolve_path("notes.txt"), PathBuf::from("notes.txt"));
+    }
+
+    #[test]
+    fn validate_path_rejects_traversal() {
+        let key = "../etc/passwd";
+        let relative_path = resolve_path(key);
+        assert!(matches!(
+            validate_path(key, &relative_path),
+            Err(AppError::PathTraversal(_))
+        ));
+    }
+
+    #[test]
+    fn validate_path_accepts_normal_keys() {
+        let key = "tk";
+        let relative_path = resolve_path(key);
+        assert!(validate_path(key, &relative_path).is_ok());
+    }
+
+    #[test]
+    fn validate_path_rejects_absolute_keys() {
+        let key = "/tmp/secret.md";
+        let relative_path = resolve_path(key);
+        assert!(matches!(
+            validate_path(key, &relative_path),
+            Err(AppError::PathTraversal(_))
+        ));
+    }
+}
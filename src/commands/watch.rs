@@ -0,0 +1,177 @@
+use crate::commands::touch::resolve_path;
+use crate::error::AppError;
+use std::path::{Path, PathBuf};
+
+/// A single change observed under `.mx/`.
+#[derive(Debug, Clone)]
+pub struct WatchEvent {
+    /// The key that maps back to `relative_path` (e.g. `"tasks.md"` -> `"tk"`),
+    /// or `None` if the path doesn't match a known alias.
+    pub key: Option<String>,
+    pub relative_path: String,
+    pub kind: ChangeKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    Created,
+    Modified,
+    Removed,
+}
+
+/// Abstracts over how filesystem change notifications are produced, so the
+/// real implementation can use OS filesystem events while tests inject a
+/// fake stream of raw changes.
+pub trait Fs {
+    /// Blocks until the next raw change under `root` is available.
+    fn next_change(&mut self) -> Option<(PathBuf, ChangeKind)>;
+}
+
+/// Watches `.mx/` for created, modified, and removed context files, turning
+/// raw filesystem notifications into `WatchEvent`s with resolved alias keys.
+pub struct Watcher<F: Fs> {
+    root: PathBuf,
+    fs: F,
+}
+
+impl<F: Fs> Watcher<F> {
+    /// `root` must be the local `.mx/` directory being watched; there is no
+    /// remote equivalent since this relies on OS filesystem-change
+    /// notifications (see `Storage::local_root`).
+    pub fn new(root: &Path, fs: F) -> Self {
+        Self {
+            root: root.to_path_buf(),
+            fs,
+        }
+    }
+
+    /// Returns the next resolved event, or `None` once the underlying `Fs`
+    /// stream ends.
+    pub fn next_event(&mut self) -> Option<WatchEvent> {
+        let (absolute_path, kind) = self.fs.next_change()?;
+        let relative_path = absolute_path
+            .strip_prefix(&self.root)
+            .unwrap_or(&absolute_path)
+            .to_path_buf();
+
+        Some(WatchEvent {
+            key: reverse_resolve(&relative_path),
+            relative_path: relative_path.display().to_string(),
+            kind,
+        })
+    }
+}
+
+/// Best-effort inverse of `resolve_path`: maps a relative path like
+/// `"tasks.md"` back to its short alias (`"tk"`), if one exists.
+fn reverse_resolve(relative_path: &Path) -> Option<String> {
+    const CANDIDATES: &[&str] = &["tk", "rq", "pdt"];
+    CANDIDATES
+        .iter()
+        .find(|alias| resolve_path(alias) == relative_path)
+        .map(|alias| alias.to_string())
+}
+
+pub use notify_fs::NotifyFs;
+
+pub fn watch(root: &Path) -> Result<NotifyFs, AppError> {
+    NotifyFs::new(root)
+}
+
+/// Real filesystem-backed `Fs` implementation, isolated in its own module so
+/// the notify dependency only needs to be reachable from here.
+mod notify_fs {
+    use super::{ChangeKind, Fs};
+    use crate::error::AppError;
+    use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher as _};
+    use std::path::{Path, PathBuf};
+    use std::sync::mpsc::{channel, Receiver};
+
+    pub struct NotifyFs {
+        _watcher: RecommendedWatcher,
+        events: Receiver<notify::Result<Event>>,
+    }
+
+    impl NotifyFs {
+        pub fn new(root: &Path) -> Result<Self, AppError> {
+            let (tx, events) = channel();
+            let mut watcher = notify::recommended_watcher(tx)
+                .map_err(|err| AppError::other(format!("failed to start watcher: {err}")))?;
+            watcher
+                .watch(root, RecursiveMode::Recursive)
+                .map_err(|err| AppError::other(format!("failed to watch {}: {err}", root.display())))?;
+            Ok(Self { _watcher: watcher, events })
+        }
+    }
+
+    impl Fs for NotifyFs {
+        fn next_change(&mut self) -> Option<(PathBuf, ChangeKind)> {
+            loop {
+                let event = self.events.recv().ok()?.ok()?;
+                let Some(path) = event.paths.into_iter().next() else {
+                    continue;
+                };
+                let kind = match event.kind {
+                    EventKind::Create(_) => ChangeKind::Created,
+                    EventKind::Modify(_) => ChangeKind::Modified,
+                    EventKind::Remove(_) => ChangeKind::Removed,
+                    _ => continue,
+                };
+                return Some((path, kind));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    struct FakeFs {
+        changes: VecDeque<(PathBuf, ChangeKind)>,
+    }
+
+    impl Fs for FakeFs {
+        fn next_change(&mut self) -> Option<(PathBuf, ChangeKind)> {
+            self.changes.pop_front()
+        }
+    }
+
+    #[test]
+    fn resolves_known_alias() {
+        let root = PathBuf::from("/project/.mx");
+        let fs = FakeFs {
+            changes: VecDeque::from([(root.join("tasks.md"), ChangeKind::Modified)]),
+        };
+        let mut watcher = Watcher { root, fs };
+
+        let event = watcher.next_event().unwrap();
+        assert_eq!(event.key.as_deref(), Some("tk"));
+        assert_eq!(event.relative_path, "tasks.md");
+        assert_eq!(event.kind, ChangeKind::Modified);
+    }
+
+    #[test]
+    fn leaves_key_unresolved_for_custom_paths() {
+        let root = PathBuf::from("/project/.mx");
+        let fs = FakeFs {
+            changes: VecDeque::from([(root.join("docs/spec.md"), ChangeKind::Created)]),
+        };
+        let mut watcher = Watcher { root, fs };
+
+        let event = watcher.next_event().unwrap();
+        assert_eq!(event.key, None);
+        assert_eq!(event.relative_path, "docs/spec.md");
+    }
+
+    #[test]
+    fn ends_when_fake_stream_is_empty() {
+        let mut watcher = Watcher {
+            root: PathBuf::from("/project/.mx"),
+            fs: FakeFs { changes: VecDeque::new() },
+        };
+
+        assert!(watcher.next_event().is_none());
+    }
+}
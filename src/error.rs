@@ -0,0 +1,57 @@
+//! Centralized error type shared by every `mix` command.
+
+use std::fmt;
+
+#[derive(Debug)]
+pub enum AppError {
+    /// A requested context file or alias could not be found.
+    NotFound(String),
+    /// A resolved path escaped the `.mx/` directory.
+    PathTraversal(String),
+    /// Any I/O failure bubbled up from `std::fs`.
+    Io(std::io::Error),
+    /// Catch-all for command-specific failures that don't need their own variant.
+    Other(String),
+}
+
+impl AppError {
+    pub fn not_found(message: impl Into<String>) -> Self {
+        AppError::NotFound(message.into())
+    }
+
+    pub fn path_traversal(key: impl Into<String>) -> Self {
+        AppError::PathTraversal(key.into())
+    }
+
+    pub fn other(message: impl Into<String>) -> Self {
+        AppError::Other(message.into())
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AppError::NotFound(msg) => write!(f, "{msg}"),
+            AppError::PathTraversal(key) => {
+                write!(f, "⚠️ path traversal detected for key: {key}")
+            }
+            AppError::Io(err) => write!(f, "{err}"),
+            AppError::Other(msg) => write!(f, "{msg}"),
+        }
+    }
+}
+
+impl std::error::Error for AppError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AppError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for AppError {
+    fn from(err: std::io::Error) -> Self {
+        AppError::Io(err)
+    }
+}
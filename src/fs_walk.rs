@@ -0,0 +1,24 @@
+//! Shared recursive directory walk, used anywhere we need every file under a
+//! root as paths relative to that root (listing, export/import).
+
+use crate::error::AppError;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Collects every regular file under `dir`, as paths relative to `root`.
+pub(crate) fn relative_files(root: &Path, dir: &Path, out: &mut Vec<PathBuf>) -> Result<(), AppError> {
+    if !dir.is_dir() {
+        return Ok(());
+    }
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            relative_files(root, &path, out)?;
+        } else {
+            out.push(path.strip_prefix(root).unwrap_or(&path).to_path_buf());
+        }
+    }
+
+    Ok(())
+}
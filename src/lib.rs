@@ -2,25 +2,33 @@
 
 pub mod error;
 
+mod atomic_write;
 mod commands;
+mod fs_walk;
+mod line_ending;
 mod storage;
 
+use commands::cat;
 use commands::clean;
 use commands::clipboard::clipboard_from_env;
 use commands::copy_snippet::CopySnippet;
 use commands::list_snippets;
+use commands::sync;
 use commands::touch;
+use commands::watch::Watcher;
 use error::AppError;
 use storage::SnippetStorage;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 pub use commands::clean::CleanOutcome;
+pub use commands::sync::{SyncAction, SyncEntry, SyncOutcome};
+pub use commands::watch::ChangeKind;
+pub use line_ending::LineEndingPolicy;
 
 #[derive(Debug, Clone)]
 pub struct CopyOutcome {
     pub key: String,
     pub relative_path: String,
-    pub absolute_path: PathBuf,
 }
 
 #[derive(Clone, Debug)]
@@ -33,13 +41,43 @@ pub struct ListEntry {
 
 pub struct TouchOutcome {
     pub key: String,
-    pub path: PathBuf,
+    pub relative_path: PathBuf,
     pub existed: bool,
     pub overwritten: bool,
 }
 
+#[derive(Debug, Clone)]
+pub struct WatchEvent {
+    pub key: Option<String>,
+    pub relative_path: String,
+    pub kind: ChangeKind,
+}
+
+/// A live handle on `.mx/`, yielding one `WatchEvent` per create/modify/remove.
+pub struct WatchHandle {
+    watcher: Watcher<commands::watch::NotifyFs>,
+}
+
+impl WatchHandle {
+    pub fn next_event(&mut self) -> Option<WatchEvent> {
+        self.watcher.next_event().map(|event| WatchEvent {
+            key: event.key,
+            relative_path: event.relative_path,
+            kind: event.kind,
+        })
+    }
+}
+
+/// Reads the contents of a context file, resolving `key` through the same
+/// alias rules as `touch_context`.
+pub fn cat_context(key: &str) -> Result<String, AppError> {
+    let storage = SnippetStorage::new_default()?;
+    cat::cat(&storage, key)
+}
+
 pub fn clean_context(key: Option<String>) -> Result<CleanOutcome, AppError> {
-    clean::clean(key)
+    let storage = SnippetStorage::new_default()?;
+    clean::clean(&storage, key)
 }
 
 pub fn copy_snippet(query: &str) -> Result<CopyOutcome, AppError> {
@@ -49,7 +87,6 @@ pub fn copy_snippet(query: &str) -> Result<CopyOutcome, AppError> {
     Ok(CopyOutcome {
         key: result.key,
         relative_path: result.relative_path,
-        absolute_path: result.absolute_path,
     })
 }
 
@@ -68,7 +105,17 @@ pub fn list_snippets() -> Result<Vec<ListEntry>, AppError> {
 }
 
 pub fn touch_context(key: &str, paste: bool, force: bool) -> Result<TouchOutcome, AppError> {
-    let outcome = touch::touch(key, force)?;
+    let storage = SnippetStorage::new_default()?;
+    let relative_path = touch::resolve_path(key);
+    // Captured before `touch` potentially truncates the file, so
+    // `PreserveExisting` still sees the file's original line ending.
+    let existing_content = if storage.exists(&relative_path)? {
+        Some(storage.read(&relative_path)?)
+    } else {
+        None
+    };
+
+    let outcome = touch::touch(&storage, key, force)?;
 
     // Paste if:
     // 1. File was just created (!existed)
@@ -76,13 +123,40 @@ pub fn touch_context(key: &str, paste: bool, force: bool) -> Result<TouchOutcome
     if paste && (!outcome.existed || outcome.overwritten) {
         let clipboard = clipboard_from_env()?;
         let content = clipboard.paste()?;
-        std::fs::write(&outcome.path, content)?;
+        let normalized = line_ending::normalize(&content, existing_content.as_deref(), line_ending::policy_from_env());
+        storage.write(&outcome.relative_path, normalized.as_bytes())?;
     }
 
     Ok(TouchOutcome {
         key: outcome.key,
-        path: outcome.path,
+        relative_path: outcome.relative_path,
         existed: outcome.existed,
         overwritten: outcome.overwritten,
     })
 }
+
+/// Copies every file under `.mx/` into `<destination_root>/.mx`, skipping
+/// files that are already byte-identical at the destination.
+pub fn export_context(destination_root: &Path) -> Result<SyncOutcome, AppError> {
+    let storage = SnippetStorage::new_default()?;
+    sync::export(&storage, destination_root)
+}
+
+/// Copies every file under `<source_root>/.mx` into `.mx/`, skipping files
+/// that are already byte-identical at the destination.
+pub fn import_context(source_root: &Path) -> Result<SyncOutcome, AppError> {
+    let storage = SnippetStorage::new_default()?;
+    sync::import(&storage, source_root)
+}
+
+/// Starts watching `.mx/` for created, modified, and removed context files.
+/// Call `next_event()` on the returned handle in a loop to react to changes.
+/// Only supported when `.mx/` is on the local filesystem.
+pub fn watch_context() -> Result<WatchHandle, AppError> {
+    let storage = SnippetStorage::new_default()?;
+    let root = storage
+        .local_root()
+        .ok_or_else(|| AppError::other("mix watch requires local storage (MX_REMOTE is set)"))?;
+    let fs = commands::watch::watch(root)?;
+    Ok(WatchHandle { watcher: Watcher::new(root, fs) })
+}
@@ -0,0 +1,101 @@
+//! Normalizes line endings on user-supplied text (e.g. pasted clipboard
+//! content) before it's written into a context file, so CRLF/LF mixing from
+//! cross-platform clipboards doesn't corrupt diffs and markdown rendering.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineEndingPolicy {
+    Lf,
+    Crlf,
+    /// Match the dominant line ending already in the destination file,
+    /// falling back to LF for new files.
+    #[default]
+    PreserveExisting,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LineEnding {
+    Lf,
+    Crlf,
+}
+
+/// Reads the policy from `MX_LINE_ENDING` (`lf` / `crlf` / `preserve`),
+/// defaulting to `PreserveExisting`.
+pub fn policy_from_env() -> LineEndingPolicy {
+    match std::env::var("MX_LINE_ENDING").ok().as_deref() {
+        Some("lf") => LineEndingPolicy::Lf,
+        Some("crlf") => LineEndingPolicy::Crlf,
+        _ => LineEndingPolicy::PreserveExisting,
+    }
+}
+
+/// Rewrites `content` to use the line ending chosen by `policy`. `existing`
+/// is the destination file's current bytes, consulted only for
+/// `PreserveExisting`.
+pub fn normalize(content: &str, existing: Option<&[u8]>, policy: LineEndingPolicy) -> String {
+    let target = match policy {
+        LineEndingPolicy::Lf => LineEnding::Lf,
+        LineEndingPolicy::Crlf => LineEnding::Crlf,
+        LineEndingPolicy::PreserveExisting => existing.map(detect).unwrap_or(LineEnding::Lf),
+    };
+    apply(content, target)
+}
+
+/// The dominant line ending is CRLF only if at least half of the line breaks
+/// are `\r\n` rather than a lone `\n`.
+fn detect(existing: &[u8]) -> LineEnding {
+    let text = String::from_utf8_lossy(existing);
+    let lf_count = text.matches('\n').count();
+    if lf_count == 0 {
+        return LineEnding::Lf;
+    }
+
+    let crlf_count = text.matches("\r\n").count();
+    if crlf_count * 2 >= lf_count {
+        LineEnding::Crlf
+    } else {
+        LineEnding::Lf
+    }
+}
+
+fn apply(content: &str, target: LineEnding) -> String {
+    let lf_only = content.replace("\r\n", "\n").replace('\r', "\n");
+    match target {
+        LineEnding::Lf => lf_only,
+        LineEnding::Crlf => lf_only.replace('\n', "\r\n"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_new_files_to_lf() {
+        let result = normalize("a\r\nb\r\n", None, LineEndingPolicy::PreserveExisting);
+        assert_eq!(result, "a\nb\n");
+    }
+
+    #[test]
+    fn preserves_crlf_files() {
+        let result = normalize("a\nb\n", Some(b"old\r\ncontent\r\n"), LineEndingPolicy::PreserveExisting);
+        assert_eq!(result, "a\r\nb\r\n");
+    }
+
+    #[test]
+    fn preserves_lf_files() {
+        let result = normalize("a\r\nb\r\n", Some(b"old\ncontent\n"), LineEndingPolicy::PreserveExisting);
+        assert_eq!(result, "a\nb\n");
+    }
+
+    #[test]
+    fn explicit_policy_overrides_existing_file() {
+        let result = normalize("a\nb\n", Some(b"old\ncontent\n"), LineEndingPolicy::Crlf);
+        assert_eq!(result, "a\r\nb\r\n");
+    }
+
+    #[test]
+    fn handles_mixed_line_endings_in_input() {
+        let result = normalize("a\r\nb\nc\rd", None, LineEndingPolicy::Lf);
+        assert_eq!(result, "a\nb\nc\nd");
+    }
+}
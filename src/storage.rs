@@ -0,0 +1,95 @@
+//! Storage backend abstraction so `.mx/` can live on the local filesystem or
+//! on a remote host.
+//!
+//! All path-resolution and alias logic (see `commands::touch`) stays
+//! backend-agnostic; only the read/write/list/remove primitives below differ
+//! per backend.
+
+mod local;
+mod ssh;
+
+use crate::error::AppError;
+use std::path::{Path, PathBuf};
+
+/// Keyed by paths relative to the `.mx/` root. Implementations must be
+/// object-safe so callers can inject a fake backend in tests.
+pub trait Storage {
+    fn read(&self, relative_path: &Path) -> Result<Vec<u8>, AppError>;
+    fn write(&self, relative_path: &Path, contents: &[u8]) -> Result<(), AppError>;
+    fn list(&self) -> Result<Vec<PathBuf>, AppError>;
+    fn remove(&self, relative_path: &Path) -> Result<(), AppError>;
+    /// Removes everything under the backend's root. Returns `false` if there
+    /// was nothing to remove.
+    fn remove_all(&self) -> Result<bool, AppError>;
+    fn exists(&self, relative_path: &Path) -> Result<bool, AppError>;
+    fn is_file(&self, relative_path: &Path) -> Result<bool, AppError>;
+
+    /// The local root directory backing this storage, if any. Only the local
+    /// backend has one; it's used by `mix watch`, which relies on OS
+    /// filesystem-change notifications that a remote backend can't provide.
+    fn local_root(&self) -> Option<&Path> {
+        None
+    }
+}
+
+pub struct SnippetStorage {
+    backend: Box<dyn Storage>,
+}
+
+impl SnippetStorage {
+    /// Builds the storage backend selected by `MX_REMOTE` (e.g.
+    /// `user@host:/path`), falling back to `<project_root>/.mx` on the local
+    /// filesystem.
+    pub fn new_default() -> Result<Self, AppError> {
+        let backend: Box<dyn Storage> = match std::env::var("MX_REMOTE") {
+            Ok(remote) => Box::new(ssh::SshStorage::parse(&remote)?),
+            Err(_) => {
+                let root = crate::commands::touch::find_project_root()?.join(".mx");
+                Box::new(local::LocalStorage::new(root))
+            }
+        };
+        Ok(Self { backend })
+    }
+
+    /// Builds storage backed by a local `.mx/` under `root`, bypassing
+    /// `MX_REMOTE` and the current working directory entirely. Tests use
+    /// this instead of `env::set_current_dir` so they don't race each other
+    /// over shared process state under the default multi-threaded test
+    /// harness.
+    #[cfg(test)]
+    pub(crate) fn at_root(root: PathBuf) -> Self {
+        Self { backend: Box::new(local::LocalStorage::new(root.join(".mx"))) }
+    }
+
+    pub fn read(&self, relative_path: &Path) -> Result<Vec<u8>, AppError> {
+        self.backend.read(relative_path)
+    }
+
+    pub fn write(&self, relative_path: &Path, contents: &[u8]) -> Result<(), AppError> {
+        self.backend.write(relative_path, contents)
+    }
+
+    pub fn list(&self) -> Result<Vec<PathBuf>, AppError> {
+        self.backend.list()
+    }
+
+    pub fn remove(&self, relative_path: &Path) -> Result<(), AppError> {
+        self.backend.remove(relative_path)
+    }
+
+    pub fn remove_all(&self) -> Result<bool, AppError> {
+        self.backend.remove_all()
+    }
+
+    pub fn exists(&self, relative_path: &Path) -> Result<bool, AppError> {
+        self.backend.exists(relative_path)
+    }
+
+    pub fn is_file(&self, relative_path: &Path) -> Result<bool, AppError> {
+        self.backend.is_file(relative_path)
+    }
+
+    pub fn local_root(&self) -> Option<&Path> {
+        self.backend.local_root()
+    }
+}
@@ -0,0 +1,73 @@
+use super::Storage;
+use crate::atomic_write::write_atomic;
+use crate::error::AppError;
+use crate::fs_walk::relative_files;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub struct LocalStorage {
+    root: PathBuf,
+}
+
+impl LocalStorage {
+    pub fn new(root: PathBuf) -> Self {
+        Self { root }
+    }
+}
+
+impl Storage for LocalStorage {
+    fn read(&self, relative_path: &Path) -> Result<Vec<u8>, AppError> {
+        Ok(fs::read(self.root.join(relative_path))?)
+    }
+
+    fn write(&self, relative_path: &Path, contents: &[u8]) -> Result<(), AppError> {
+        write_atomic(&self.root.join(relative_path), contents)
+    }
+
+    fn list(&self) -> Result<Vec<PathBuf>, AppError> {
+        let mut paths = Vec::new();
+        relative_files(&self.root, &self.root, &mut paths)?;
+        Ok(paths)
+    }
+
+    fn remove(&self, relative_path: &Path) -> Result<(), AppError> {
+        let target = self.root.join(relative_path);
+        fs::remove_file(&target)?;
+
+        // Prune now-empty parent directories up to (but not including) root.
+        if let Some(mut parent) = target.parent() {
+            while parent.starts_with(&self.root) && parent != self.root {
+                if fs::remove_dir(parent).is_err() {
+                    break;
+                }
+                match parent.parent() {
+                    Some(p) => parent = p,
+                    None => break,
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn remove_all(&self) -> Result<bool, AppError> {
+        if self.root.exists() {
+            fs::remove_dir_all(&self.root)?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    fn exists(&self, relative_path: &Path) -> Result<bool, AppError> {
+        Ok(self.root.join(relative_path).exists())
+    }
+
+    fn is_file(&self, relative_path: &Path) -> Result<bool, AppError> {
+        Ok(self.root.join(relative_path).is_file())
+    }
+
+    fn local_root(&self) -> Option<&Path> {
+        Some(&self.root)
+    }
+}
@@ -0,0 +1,202 @@
+//! Remote storage backend, selected with `MX_REMOTE=user@host:/path`.
+//!
+//! Shells out to the system `ssh` binary rather than embedding a SSH client,
+//! so it picks up the user's existing keys, agent, and `~/.ssh/config`
+//! exactly like any other `ssh` invocation would.
+
+use super::Storage;
+use crate::error::AppError;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+pub struct SshStorage {
+    host: String,
+    root: String,
+}
+
+impl SshStorage {
+    /// Parses `MX_REMOTE` values of the form `user@host:/path`.
+    pub fn parse(remote: &str) -> Result<Self, AppError> {
+        let (host, root) = remote.split_once(':').ok_or_else(|| {
+            AppError::other(format!(
+                "MX_REMOTE must look like user@host:/path, got: {remote}"
+            ))
+        })?;
+        Ok(Self {
+            host: host.to_string(),
+            root: root.trim_end_matches('/').to_string(),
+        })
+    }
+
+    /// Joins `relative_path`'s components with `/` explicitly rather than
+    /// `Path::display()`, which would render `\`-separated components on
+    /// Windows and corrupt the Unix shell command sent over `ssh`.
+    fn remote_path(&self, relative_path: &Path) -> String {
+        let joined = relative_path
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy().into_owned())
+            .collect::<Vec<_>>()
+            .join("/");
+        format!("{}/{}", self.root, joined)
+    }
+
+    fn run(&self, remote_command: &str) -> Result<std::process::Output, AppError> {
+        Command::new("ssh")
+            .arg(&self.host)
+            .arg(remote_command)
+            .stdin(Stdio::null())
+            .output()
+            .map_err(|err| AppError::other(format!("failed to run ssh: {err}")))
+    }
+
+    fn run_with_stdin(&self, remote_command: &str, input: &[u8]) -> Result<std::process::Output, AppError> {
+        let mut child = Command::new("ssh")
+            .arg(&self.host)
+            .arg(remote_command)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .map_err(|err| AppError::other(format!("failed to run ssh: {err}")))?;
+
+        child
+            .stdin
+            .take()
+            .expect("stdin was piped")
+            .write_all(input)?;
+
+        child
+            .wait_with_output()
+            .map_err(|err| AppError::other(format!("failed to run ssh: {err}")))
+    }
+}
+
+impl Storage for SshStorage {
+    fn read(&self, relative_path: &Path) -> Result<Vec<u8>, AppError> {
+        let path = self.remote_path(relative_path);
+        let output = self.run(&format!("cat {}", shell_quote(&path)))?;
+        if !output.status.success() {
+            return Err(AppError::not_found(format!(
+                "⚠️ Context file not found on {}: {}",
+                self.host, relative_path.display()
+            )));
+        }
+        Ok(output.stdout)
+    }
+
+    fn write(&self, relative_path: &Path, contents: &[u8]) -> Result<(), AppError> {
+        let path = self.remote_path(relative_path);
+        let temp_path = format!("{path}.mxtmp");
+        let dir = Path::new(&path)
+            .parent()
+            .map(|p| p.display().to_string())
+            .unwrap_or_default();
+        let remote_command = format!(
+            "mkdir -p {} && cat > {} && mv -f {} {}",
+            shell_quote(&dir),
+            shell_quote(&temp_path),
+            shell_quote(&temp_path),
+            shell_quote(&path),
+        );
+        let output = self.run_with_stdin(&remote_command, contents)?;
+        if !output.status.success() {
+            return Err(AppError::other(format!(
+                "failed to write {} on {}",
+                relative_path.display(),
+                self.host
+            )));
+        }
+        Ok(())
+    }
+
+    fn list(&self) -> Result<Vec<PathBuf>, AppError> {
+        let output = self.run(&format!(
+            "find {} -type f",
+            shell_quote(&self.root)
+        ))?;
+        if !output.status.success() {
+            return Ok(Vec::new());
+        }
+        let prefix = format!("{}/", self.root);
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(|line| PathBuf::from(line.strip_prefix(&prefix).unwrap_or(line)))
+            .collect())
+    }
+
+    fn remove(&self, relative_path: &Path) -> Result<(), AppError> {
+        let path = self.remote_path(relative_path);
+        let output = self.run(&format!("rm -f {}", shell_quote(&path)))?;
+        if !output.status.success() {
+            return Err(AppError::other(format!(
+                "failed to remove {} on {}",
+                relative_path.display(),
+                self.host
+            )));
+        }
+        Ok(())
+    }
+
+    fn remove_all(&self) -> Result<bool, AppError> {
+        let existed = self.exists(Path::new(""))?;
+        let output = self.run(&format!("rm -rf {}", shell_quote(&self.root)))?;
+        if !output.status.success() {
+            return Err(AppError::other(format!(
+                "failed to remove .mx directory on {}",
+                self.host
+            )));
+        }
+        Ok(existed)
+    }
+
+    fn exists(&self, relative_path: &Path) -> Result<bool, AppError> {
+        let path = self.remote_path(relative_path);
+        let output = self.run(&format!("test -e {}", shell_quote(&path)))?;
+        Ok(output.status.success())
+    }
+
+    fn is_file(&self, relative_path: &Path) -> Result<bool, AppError> {
+        let path = self.remote_path(relative_path);
+        let output = self.run(&format!("test -f {}", shell_quote(&path)))?;
+        Ok(output.status.success())
+    }
+}
+
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_splits_host_and_root() {
+        let storage = SshStorage::parse("user@host:/srv/project").unwrap();
+        assert_eq!(storage.host, "user@host");
+        assert_eq!(storage.root, "/srv/project");
+    }
+
+    #[test]
+    fn parse_trims_trailing_slash_from_root() {
+        let storage = SshStorage::parse("user@host:/srv/project/").unwrap();
+        assert_eq!(storage.root, "/srv/project");
+    }
+
+    #[test]
+    fn parse_rejects_missing_colon() {
+        assert!(SshStorage::parse("user@host").is_err());
+    }
+
+    #[test]
+    fn remote_path_joins_nested_components_with_forward_slashes() {
+        let storage = SshStorage::parse("user@host:/srv/project").unwrap();
+        let path = storage.remote_path(Path::new("pending/tasks.md"));
+        assert_eq!(path, "/srv/project/pending/tasks.md");
+    }
+
+    #[test]
+    fn shell_quote_escapes_single_quotes() {
+        assert_eq!(shell_quote("it's"), "'it'\\''s'");
+    }
+}